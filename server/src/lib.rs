@@ -22,8 +22,12 @@ pub struct Player {
     online: bool,
     color: Color,
     position: Position,
+    velocity: Position, // movement delta since the last player_moved update, used for sprint detection
+    knockback: Position, // outstanding knockback impulse, integrated into position by game_tick
     score: u32,
     radius: f32,
+    last_attack_tick: u64,
+    team: u8, // 0 = free-for-all, no team
 }
 
 #[table(name = food, public)]
@@ -43,6 +47,56 @@ pub struct FoodSpawnSchedule {
     scheduled_at: ScheduleAt,
 }
 
+#[table(name = game_tick_schedule, scheduled(game_tick))]
+pub struct GameTickSchedule {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+}
+
+// Singleton table toggling team mode on/off and setting how many teams are in play
+#[table(name = game_config, public)]
+pub struct GameConfig {
+    #[primary_key]
+    id: u8,
+    team_mode: bool,
+    team_count: u8,
+}
+
+const GAME_CONFIG_ID: u8 = 0;
+
+// Singleton table tracking the largest radius any player has reached, so collision scans
+// know how far a neighboring cell search needs to reach to catch the biggest possible opponent.
+// The value is only ever raised; it is not lowered when players decay, respawn or disconnect.
+#[table(name = world_stats)]
+pub struct WorldStats {
+    #[primary_key]
+    id: u8,
+    max_player_radius: f32,
+}
+
+const WORLD_STATS_ID: u8 = 0;
+
+// Spatial index mapping each food item to the grid cell it currently occupies, so
+// collision checks can scan a handful of nearby cells instead of the whole food table.
+#[table(name = food_cell)]
+pub struct FoodCell {
+    #[primary_key]
+    food_id: u64,
+    #[index(btree)]
+    cell_key: i64,
+}
+
+// Spatial index mapping each online player to the grid cell they currently occupy.
+#[table(name = player_cell)]
+pub struct PlayerCell {
+    #[primary_key]
+    identity: Identity,
+    #[index(btree)]
+    cell_key: i64,
+}
+
 const PLAYER_START_SIZE: f32 = 20.0;
 
 #[reducer(client_connected)]
@@ -56,8 +110,9 @@ pub fn client_connected(ctx: &ReducerContext) {
     }
     else {
         // Player does not exist, create a new player
-        let spawn_position = Position { x: 250.0, y: 250.0 }; // Start in center of 500x500 world
-        
+        let team = assign_team(ctx);
+        let spawn_position = pick_spawn_position(ctx, team);
+
         ctx.db.player().insert(Player {
             identity: ctx.sender,
             online: true,
@@ -67,10 +122,15 @@ pub fn client_connected(ctx: &ReducerContext) {
                 b: ctx.rng().gen(),
             },
             position: spawn_position,
+            velocity: Position { x: 0.0, y: 0.0 },
+            knockback: Position { x: 0.0, y: 0.0 },
             score: 0,
             radius: PLAYER_START_SIZE,
+            last_attack_tick: 0,
+            team,
         });
-        
+        update_player_cell(ctx, ctx.sender, spawn_position);
+
         log::info!("New player created: {:?}", ctx.sender);
     }
 }
@@ -92,9 +152,12 @@ pub fn client_disconnected(ctx: &ReducerContext) {
 // Called when a player moves
 pub fn player_moved(ctx: &ReducerContext, position: Position) {
     if let Some(player) = ctx.db.player().identity().find(ctx.sender) {
-        // Update the player's position
-        ctx.db.player().identity().update(Player { position, ..player });
-        
+        // Update the player's position, tracking velocity as the movement delta since
+        // the last update so combat can tell a sprinting player from a crawling one
+        let velocity = Position { x: position.x - player.position.x, y: position.y - player.position.y };
+        ctx.db.player().identity().update(Player { position, velocity, ..player });
+        update_player_cell(ctx, ctx.sender, position);
+
         // Check for food collisions after moving
         check_food_collisions(ctx, position, player.score);
         
@@ -113,6 +176,30 @@ const WORLD_SIZE: f32 = 500.0;
 const FOOD_SPAWN_INTERVAL_SECONDS: u64 = 2; // 2 seconds
 const FOOD_RADIUS: f32 = 5.0;
 
+// Spawn placement configuration
+const SPAWN_CANDIDATE_COUNT: u32 = 16;
+const MIN_SPAWN_DIST: f32 = 100.0;
+const SPAWN_SAFE_BONUS: f32 = 100.0;
+const SPAWN_PRIORITY_WEIGHT: f32 = 1000.0;
+const TEAM_PROXIMITY_WEIGHT: f32 = 0.5; // score boost per unit closer to the nearest living teammate
+const ENEMY_DISTANCE_WEIGHT: f32 = 0.5; // score boost per unit farther from the nearest enemy
+
+// Spatial grid configuration
+const CELL_SIZE: f32 = 50.0;
+
+// Combat configuration
+const ATTACK_COOLDOWN_MICROS: u64 = 300_000; // 300ms between hits from the same attacker
+const SPRINT_SPEED_THRESHOLD: f32 = 10.0; // distance moved in one update to count as sprinting
+const SPRINT_KNOCKBACK_MULTIPLIER: f32 = 1.5;
+const KNOCKBACK_FORCE: f32 = 1.5; // knockback distance per point of attacker radius
+const MASS_TRANSFER_FRACTION: f32 = 0.1; // fraction of victim radius chipped off per hit
+const MIN_PLAYER_RADIUS: f32 = 10.0; // victim is eaten once hits shrink it below this
+
+// Game tick configuration
+const GAME_TICK_INTERVAL_MILLIS: u64 = 50;
+const MASS_DECAY_RATE: f32 = 0.0005; // fraction of radius shed per tick
+const KNOCKBACK_DECAY: f32 = 0.85; // friction applied to outstanding knockback per tick so a hit fades out instead of sliding forever
+
 // Helper function to calculate distance between two positions
 fn distance(pos1: Position, pos2: Position) -> f32 {
     let dx = pos1.x - pos2.x;
@@ -120,6 +207,171 @@ fn distance(pos1: Position, pos2: Position) -> f32 {
     (dx * dx + dy * dy).sqrt()
 }
 
+// Helper function to get the current tick, measured in microseconds since the Unix epoch
+fn current_tick(ctx: &ReducerContext) -> u64 {
+    ctx.timestamp.to_micros_since_unix_epoch() as u64
+}
+
+// Helper function to normalize a vector, returning a zero vector if it has no length
+fn normalize(v: Position) -> Position {
+    let len = (v.x * v.x + v.y * v.y).sqrt();
+    if len < f32::EPSILON {
+        Position { x: 0.0, y: 0.0 }
+    } else {
+        Position { x: v.x / len, y: v.y / len }
+    }
+}
+
+// Helper function to clamp a position to the world bounds
+fn clamp_to_world(position: Position) -> Position {
+    Position {
+        x: position.x.clamp(-WORLD_SIZE, WORLD_SIZE),
+        y: position.y.clamp(-WORLD_SIZE, WORLD_SIZE),
+    }
+}
+
+// Helper function to map a world coordinate onto a grid cell index
+fn cell_coord(v: f32) -> i32 {
+    (v / CELL_SIZE).floor() as i32
+}
+
+// Helper function to pack a (cell_x, cell_y) pair into a single indexable key
+fn cell_key(cell_x: i32, cell_y: i32) -> i64 {
+    ((cell_x as i64) << 32) | (cell_y as i64 & 0xFFFF_FFFF)
+}
+
+// Helper function to work out how many cells in each direction a search radius spans
+fn cell_span(radius: f32) -> i32 {
+    ((radius / CELL_SIZE).ceil() as i32).max(1)
+}
+
+// Helper function to upsert a food item's position into the food_cell spatial index
+fn update_food_cell(ctx: &ReducerContext, food_id: u64, position: Position) {
+    let key = cell_key(cell_coord(position.x), cell_coord(position.y));
+    if ctx.db.food_cell().food_id().find(food_id).is_some() {
+        ctx.db.food_cell().food_id().update(FoodCell { food_id, cell_key: key });
+    } else {
+        ctx.db.food_cell().insert(FoodCell { food_id, cell_key: key });
+    }
+}
+
+// Helper function to upsert a player's position into the player_cell spatial index
+fn update_player_cell(ctx: &ReducerContext, identity: Identity, position: Position) {
+    let key = cell_key(cell_coord(position.x), cell_coord(position.y));
+    if ctx.db.player_cell().identity().find(identity).is_some() {
+        ctx.db.player_cell().identity().update(PlayerCell { identity, cell_key: key });
+    } else {
+        ctx.db.player_cell().insert(PlayerCell { identity, cell_key: key });
+    }
+}
+
+// Helper function to widen the tracked max player radius if this radius now exceeds it,
+// so the player-collision scan span always reaches the biggest opponent on the board.
+fn note_player_radius(ctx: &ReducerContext, radius: f32) {
+    if let Some(stats) = ctx.db.world_stats().id().find(WORLD_STATS_ID) {
+        if radius > stats.max_player_radius {
+            ctx.db.world_stats().id().update(WorldStats { max_player_radius: radius, ..stats });
+        }
+    } else {
+        ctx.db.world_stats().insert(WorldStats { id: WORLD_STATS_ID, max_player_radius: radius });
+    }
+}
+
+// Helper function to read the current max player radius, defaulting to the starting size
+fn max_player_radius(ctx: &ReducerContext) -> f32 {
+    ctx.db.world_stats().id().find(WORLD_STATS_ID)
+        .map(|stats| stats.max_player_radius)
+        .unwrap_or(PLAYER_START_SIZE)
+}
+
+// Helper function to assign a newly-joining player to a team. Returns 0 (free-for-all)
+// when team mode is off, otherwise returns whichever team currently has the fewest players.
+fn assign_team(ctx: &ReducerContext) -> u8 {
+    let Some(config) = ctx.db.game_config().id().find(GAME_CONFIG_ID) else {
+        return 0;
+    };
+    if !config.team_mode || config.team_count == 0 {
+        return 0;
+    }
+
+    let mut smallest_team = 1;
+    let mut smallest_count = u32::MAX;
+    for team in 1..=config.team_count {
+        // Stale offline accounts are never deleted, so only count the active roster -
+        // otherwise a team that's actually empty can still look "full" of ghosts.
+        let count = ctx.db.player().iter().filter(|p| p.online && p.team == team).count() as u32;
+        if count < smallest_count {
+            smallest_count = count;
+            smallest_team = team;
+        }
+    }
+    smallest_team
+}
+
+// Helper function to pick a spawn position that favors isolation from other online players.
+// Generates several random candidates and scores each by its distance to the nearest
+// online player (teammates excluded in team mode), preferring candidates that clear MIN_SPAWN_DIST so respawning players
+// aren't dropped right back on top of whoever just ate them. In team mode (team != 0),
+// candidates additionally score higher when they sit close to living teammates and far
+// from enemies, so teams spawn and regroup together instead of scattering across the map.
+fn pick_spawn_position(ctx: &ReducerContext, team: u8) -> Position {
+    let mut best_position = Position { x: 250.0, y: 250.0 };
+    let mut best_score = f32::MIN;
+
+    for _ in 0..SPAWN_CANDIDATE_COUNT {
+        let candidate = Position {
+            x: ctx.rng().gen_range(-WORLD_SIZE..WORLD_SIZE),
+            y: ctx.rng().gen_range(-WORLD_SIZE..WORLD_SIZE),
+        };
+
+        let mut shortest = f32::MAX;
+        let mut nearest_teammate = f32::MAX;
+        let mut nearest_enemy = f32::MAX;
+        for other_player in ctx.db.player().iter() {
+            if !other_player.online {
+                continue;
+            }
+            let dist = distance(candidate, other_player.position);
+            let is_teammate = team != 0 && other_player.team == team;
+            // Teammates don't count against the safe-distance bonus below, or a candidate
+            // sitting right next to the squad would always lose to a far-off isolated spot
+            if !is_teammate && dist < shortest {
+                shortest = dist;
+            }
+            if team != 0 {
+                if is_teammate && dist < nearest_teammate {
+                    nearest_teammate = dist;
+                } else if other_player.team != 0 && other_player.team != team && dist < nearest_enemy {
+                    nearest_enemy = dist;
+                }
+            }
+        }
+        if shortest == f32::MAX {
+            shortest = 0.0;
+        }
+
+        let mut priority = 0.0;
+        if shortest > MIN_SPAWN_DIST {
+            priority += SPAWN_SAFE_BONUS;
+        }
+
+        let mut score = priority * SPAWN_PRIORITY_WEIGHT + shortest;
+        if nearest_teammate < f32::MAX {
+            score -= nearest_teammate * TEAM_PROXIMITY_WEIGHT;
+        }
+        if nearest_enemy < f32::MAX {
+            score += nearest_enemy * ENEMY_DISTANCE_WEIGHT;
+        }
+
+        if score > best_score {
+            best_score = score;
+            best_position = candidate;
+        }
+    }
+
+    best_position
+}
+
 // Helper function to check for food collisions and handle eating
 fn check_food_collisions(ctx: &ReducerContext, player_position: Position, current_score: u32) {
     let Some(player) = ctx.db.player().identity().find(ctx.sender) else {
@@ -128,30 +380,46 @@ fn check_food_collisions(ctx: &ReducerContext, player_position: Position, curren
     };
     let collision_distance = player.radius + FOOD_RADIUS;
     let mut foods_to_eat = Vec::new();
-    
-    // Find all food items that collide with the player
-    for food in ctx.db.food().iter() {
-        if distance(player_position, food.position) <= collision_distance {
-            foods_to_eat.push(food.id);
+
+    // Only scan the grid cells that the collision radius could possibly reach,
+    // instead of every food item in the world.
+    let center_x = cell_coord(player_position.x);
+    let center_y = cell_coord(player_position.y);
+    let span = cell_span(collision_distance);
+
+    for cell_x in (center_x - span)..=(center_x + span) {
+        for cell_y in (center_y - span)..=(center_y + span) {
+            let key = cell_key(cell_x, cell_y);
+            for cell_entry in ctx.db.food_cell().cell_key().filter(key) {
+                let Some(food) = ctx.db.food().id().find(cell_entry.food_id) else {
+                    continue;
+                };
+                if distance(player_position, food.position) <= collision_distance {
+                    foods_to_eat.push(food.id);
+                }
+            }
         }
     }
-    
+
     // Eat all colliding food items
     if !foods_to_eat.is_empty() {
         let foods_eaten = foods_to_eat.len() as u32;
-        
+
         // Remove all eaten food items
         for food_id in foods_to_eat {
             ctx.db.food().id().delete(food_id);
+            ctx.db.food_cell().food_id().delete(food_id);
         }
         
         // Update player's score & radius
         if let Some(player) = ctx.db.player().identity().find(ctx.sender) {
-            ctx.db.player().identity().update(Player { 
-                score: current_score + foods_eaten, 
-                radius: player.radius + foods_eaten as f32,
-                ..player 
+            let new_radius = player.radius + foods_eaten as f32;
+            ctx.db.player().identity().update(Player {
+                score: current_score + foods_eaten,
+                radius: new_radius,
+                ..player
             });
+            note_player_radius(ctx, new_radius);
         }
         
         log::info!("Player {:?} ate {} food items. New score: {}", 
@@ -166,35 +434,121 @@ fn check_player_collisions(ctx: &ReducerContext, player_position: Position) {
         return;
     };
     
-    // Check collisions with other players
-    for other_player in ctx.db.player().iter() {
-        if other_player.identity == ctx.sender || !other_player.online {
-            continue; // Skip self and offline players
-        }
+    // Only scan the grid cells that this player's collision reach could cover, instead of
+    // every online player. The span has to reach as far as the largest player on the board,
+    // not just this player's own radius, or a small player walking up to an idle giant (who
+    // never moves and thus never re-triggers its own check) would never be detected.
+    let center_x = cell_coord(player_position.x);
+    let center_y = cell_coord(player_position.y);
+    let span = cell_span(player.radius.max(max_player_radius(ctx)));
 
-        let collision_distance = player.radius.max(other_player.radius);
-        if distance(player_position, other_player.position) <= collision_distance {
-            let radius_diff = player.radius - other_player.radius;
-            if radius_diff.abs() > 5.0 {
-            let (eater, eaten) = if radius_diff > 0.0 {
-                (ctx.sender, other_player.identity)
-            } else {
-                (other_player.identity, ctx.sender)
-            };
-            // Respawn the eaten player with starting stats
-            ctx.db.player().identity().update(Player {
-                identity: eaten,
-                online: true,
-                color: Color {
-                    r: ctx.rng().gen(),
-                    g: ctx.rng().gen(),
-                    b: ctx.rng().gen(),
-                },
-                position: Position { x: 250.0, y: 250.0 },
-                score: 0,
-                radius: PLAYER_START_SIZE,
-            });
-            log::info!("Player {:?} ate player {:?}", eater, eaten);
+    for cell_x in (center_x - span)..=(center_x + span) {
+        for cell_y in (center_y - span)..=(center_y + span) {
+            let key = cell_key(cell_x, cell_y);
+            for cell_entry in ctx.db.player_cell().cell_key().filter(key) {
+                if cell_entry.identity == ctx.sender {
+                    continue; // Skip self
+                }
+                let Some(other_player) = ctx.db.player().identity().find(cell_entry.identity) else {
+                    continue;
+                };
+                if !other_player.online {
+                    continue; // Skip offline players
+                }
+                if player.team != 0 && player.team == other_player.team {
+                    continue; // Teammates pass through each other
+                }
+
+                let collision_distance = player.radius.max(other_player.radius);
+                if distance(player_position, other_player.position) > collision_distance {
+                    continue;
+                }
+
+                let radius_diff = player.radius - other_player.radius;
+                if radius_diff.abs() <= 5.0 {
+                    continue; // Too evenly matched to start a fight
+                }
+
+                let (attacker_identity, attacker_position, attacker_velocity, attacker_radius, attacker_last_attack, victim_identity, victim_position, victim_radius, victim_team) =
+                    if radius_diff > 0.0 {
+                        (ctx.sender, player.position, player.velocity, player.radius, player.last_attack_tick,
+                         other_player.identity, other_player.position, other_player.radius, other_player.team)
+                    } else {
+                        (other_player.identity, other_player.position, other_player.velocity, other_player.radius, other_player.last_attack_tick,
+                         ctx.sender, player.position, player.radius, player.team)
+                    };
+
+                // The attacker can only land a hit once their cooldown has expired
+                let tick = current_tick(ctx);
+                if tick.saturating_sub(attacker_last_attack) < ATTACK_COOLDOWN_MICROS {
+                    continue;
+                }
+
+                // Knock the victim back along the attacker-to-victim vector, scaled by the
+                // attacker's size, with a bonus if the attacker was sprinting into the hit
+                let push_direction = normalize(Position {
+                    x: victim_position.x - attacker_position.x,
+                    y: victim_position.y - attacker_position.y,
+                });
+                let attacker_speed = distance(Position { x: 0.0, y: 0.0 }, attacker_velocity);
+                let mut knockback_distance = attacker_radius * KNOCKBACK_FORCE;
+                if attacker_speed > SPRINT_SPEED_THRESHOLD {
+                    knockback_distance *= SPRINT_KNOCKBACK_MULTIPLIER;
+                }
+                let knockback = Position {
+                    x: push_direction.x * knockback_distance,
+                    y: push_direction.y * knockback_distance,
+                };
+                let knocked_position = clamp_to_world(Position {
+                    x: victim_position.x + knockback.x,
+                    y: victim_position.y + knockback.y,
+                });
+
+                // Chip away at the victim's mass and transfer it to the attacker
+                let mass_transfer = victim_radius * MASS_TRANSFER_FRACTION;
+                let victim_radius = victim_radius - mass_transfer;
+                let attacker_radius = attacker_radius + mass_transfer;
+
+                if let Some(attacker_row) = ctx.db.player().identity().find(attacker_identity) {
+                    ctx.db.player().identity().update(Player {
+                        radius: attacker_radius,
+                        last_attack_tick: tick,
+                        ..attacker_row
+                    });
+                    note_player_radius(ctx, attacker_radius);
+                }
+
+                if victim_radius < MIN_PLAYER_RADIUS {
+                    // The victim has been whittled down enough to be eaten - respawn it
+                    let spawn_position = pick_spawn_position(ctx, victim_team);
+                    ctx.db.player().identity().update(Player {
+                        identity: victim_identity,
+                        online: true,
+                        color: Color {
+                            r: ctx.rng().gen(),
+                            g: ctx.rng().gen(),
+                            b: ctx.rng().gen(),
+                        },
+                        position: spawn_position,
+                        velocity: Position { x: 0.0, y: 0.0 },
+                        knockback: Position { x: 0.0, y: 0.0 },
+                        score: 0,
+                        radius: PLAYER_START_SIZE,
+                        last_attack_tick: 0,
+                        team: victim_team,
+                    });
+                    update_player_cell(ctx, victim_identity, spawn_position);
+                    log::info!("Player {:?} ate player {:?}", attacker_identity, victim_identity);
+                } else if let Some(victim_row) = ctx.db.player().identity().find(victim_identity) {
+                    ctx.db.player().identity().update(Player {
+                        position: knocked_position,
+                        knockback,
+                        radius: victim_radius,
+                        ..victim_row
+                    });
+                    update_player_cell(ctx, victim_identity, knocked_position);
+                    log::info!("Player {:?} hit player {:?} for {:.2} knockback", attacker_identity, victim_identity, knockback_distance);
+                }
             }
         }
     }
@@ -211,6 +565,82 @@ pub fn init(ctx: &ReducerContext) {
     });
     
     log::info!("Food spawner started and will run every {} seconds", FOOD_SPAWN_INTERVAL_SECONDS);
+
+    // Create the scheduled game tick that advances the world every 50ms
+    let tick_interval = TimeDuration::from_micros((GAME_TICK_INTERVAL_MILLIS * 1_000) as i64);
+    ctx.db.game_tick_schedule().insert(GameTickSchedule {
+        scheduled_id: 0, // Auto-incremented
+        scheduled_at: tick_interval.into(), // This creates a looping schedule
+    });
+
+    log::info!("Game tick loop started and will run every {} ms", GAME_TICK_INTERVAL_MILLIS);
+
+    // Team mode is off by default so the game starts as free-for-all
+    ctx.db.game_config().insert(GameConfig {
+        id: GAME_CONFIG_ID,
+        team_mode: false,
+        team_count: 0,
+    });
+
+    ctx.db.world_stats().insert(WorldStats {
+        id: WORLD_STATS_ID,
+        max_player_radius: PLAYER_START_SIZE,
+    });
+}
+
+#[reducer]
+// Toggles team mode and sets how many teams are in play
+pub fn configure_teams(ctx: &ReducerContext, team_mode: bool, team_count: u8) {
+    if let Some(config) = ctx.db.game_config().id().find(GAME_CONFIG_ID) {
+        ctx.db.game_config().id().update(GameConfig { team_mode, team_count, ..config });
+    } else {
+        ctx.db.game_config().insert(GameConfig { id: GAME_CONFIG_ID, team_mode, team_count });
+    }
+
+    log::info!("Team mode set to {} with {} teams", team_mode, team_count);
+}
+
+#[reducer]
+// Advances the authoritative world state every tick - integrates outstanding knockback into
+// position and applies mass decay - called by the scheduler
+pub fn game_tick(ctx: &ReducerContext, _arg: GameTickSchedule) {
+    // Ensure only the scheduler can call this reducer
+    if ctx.sender != ctx.identity() {
+        log::warn!("Reducer `game_tick` may not be invoked by clients, only via scheduling");
+        return;
+    }
+
+    for player in ctx.db.player().iter() {
+        if !player.online {
+            continue;
+        }
+
+        // Only knockback is integrated here - `velocity` is the player's own movement delta
+        // from player_moved and is never re-applied, or client input would overshoot every tick
+        let new_position = clamp_to_world(Position {
+            x: player.position.x + player.knockback.x,
+            y: player.position.y + player.knockback.y,
+        });
+        let new_knockback = Position {
+            x: player.knockback.x * KNOCKBACK_DECAY,
+            y: player.knockback.y * KNOCKBACK_DECAY,
+        };
+        // Floor decay at MIN_PLAYER_RADIUS, not PLAYER_START_SIZE - otherwise decay would pull a
+        // combat-damaged player's radius back up past the kill threshold every tick and the
+        // eaten/respawn branch in check_player_collisions could never fire
+        let new_radius = (player.radius - player.radius * MASS_DECAY_RATE).max(MIN_PLAYER_RADIUS);
+
+        let identity = player.identity;
+        // score stays derived from food eaten (see check_food_collisions) - don't clobber it
+        // here, or a fresh spawn's score would jump to its starting radius before eating anything
+        ctx.db.player().identity().update(Player {
+            position: new_position,
+            knockback: new_knockback,
+            radius: new_radius,
+            ..player
+        });
+        update_player_cell(ctx, identity, new_position);
+    }
 }
 
 #[reducer]
@@ -242,11 +672,13 @@ pub fn spawn_food(ctx: &ReducerContext, _arg: FoodSpawnSchedule) {
             };
             
             // Insert the new food item
-            ctx.db.food().insert(Food {
+            let food_position = Position { x, y };
+            let food = ctx.db.food().insert(Food {
                 id: 0, // Auto-incremented
-                position: Position { x, y },
+                position: food_position,
                 color,
             });
+            update_food_cell(ctx, food.id, food_position);
         }
         
         log::info!("Spawned {} food items. Total food count: {}", spawn_count, current_food_count + spawn_count);